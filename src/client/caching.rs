@@ -0,0 +1,324 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::{Auth, ByteStream, Executor};
+use crate::error::Error;
+
+/// Where [`CachingExecutor`] stores its serialized responses.
+///
+/// An implementation only has to deal with opaque bytes; `CachingExecutor` takes care of
+/// deriving cache keys and (de)serializing the typed response.
+#[async_trait]
+pub trait CacheStore: Clone + Send + Sync + 'static {
+    /// Look up `key`, returning `None` on a miss or an expired entry.
+    async fn get(&self, key: &str) -> Option<Vec<u8>>;
+
+    /// Insert `value` under `key`, valid until `ttl` elapses if given.
+    async fn set(&self, key: String, value: Vec<u8>, ttl: Option<Duration>);
+}
+
+struct Entry {
+    value: Vec<u8>,
+    inserted_at: Instant,
+    ttl: Option<Duration>,
+    last_used: Instant,
+}
+
+impl Entry {
+    fn is_expired(&self) -> bool {
+        self.ttl
+            .is_some_and(|ttl| self.inserted_at.elapsed() > ttl)
+    }
+}
+
+/// The default [`CacheStore`]: an in-memory `HashMap`, optionally bounded to `max_entries` with
+/// least-recently-used eviction.
+#[derive(Clone)]
+pub struct InMemoryCacheStore {
+    entries: std::sync::Arc<Mutex<HashMap<String, Entry>>>,
+    max_entries: Option<usize>,
+}
+
+impl InMemoryCacheStore {
+    /// An unbounded in-memory cache.
+    pub fn new() -> Self {
+        Self {
+            entries: Default::default(),
+            max_entries: None,
+        }
+    }
+
+    /// An in-memory cache that evicts the least-recently-used entry once it holds more than
+    /// `max_entries`.
+    pub fn with_max_entries(max_entries: usize) -> Self {
+        Self {
+            entries: Default::default(),
+            max_entries: Some(max_entries),
+        }
+    }
+}
+
+impl Default for InMemoryCacheStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl CacheStore for InMemoryCacheStore {
+    async fn get(&self, key: &str) -> Option<Vec<u8>> {
+        let mut entries = self.entries.lock().unwrap();
+        let expired = entries.get(key).is_some_and(Entry::is_expired);
+        if expired {
+            entries.remove(key);
+            return None;
+        }
+        let entry = entries.get_mut(key)?;
+        entry.last_used = Instant::now();
+        Some(entry.value.clone())
+    }
+
+    async fn set(&self, key: String, value: Vec<u8>, ttl: Option<Duration>) {
+        let mut entries = self.entries.lock().unwrap();
+        if self.max_entries == Some(0) {
+            return;
+        }
+        if let Some(max_entries) = self.max_entries {
+            while entries.len() >= max_entries && !entries.contains_key(&key) {
+                let lru_key = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone());
+                let Some(lru_key) = lru_key else { break };
+                entries.remove(&lru_key);
+            }
+        }
+        let now = Instant::now();
+        entries.insert(
+            key,
+            Entry {
+                value,
+                inserted_at: now,
+                ttl,
+                last_used: now,
+            },
+        );
+    }
+}
+
+/// Wraps an [`Executor`], caching deserialized responses in a [`CacheStore`] keyed by a
+/// command's path and sorted query parameters.
+///
+/// Commands that opt out via [`Command::cacheable`](crate::prelude::Command::cacheable)
+/// returning `false` always bypass the cache. A hit returns the typed response without a network
+/// round-trip; a miss executes against the inner executor and populates the cache.
+#[derive(Clone)]
+pub struct CachingExecutor<E, S = InMemoryCacheStore> {
+    inner: E,
+    store: S,
+    ttl: Option<Duration>,
+}
+
+impl<E, S: Default> CachingExecutor<E, S> {
+    /// Wrap `inner`, caching responses in the default store with no expiry.
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            store: S::default(),
+            ttl: None,
+        }
+    }
+}
+
+impl<E, S> CachingExecutor<E, S> {
+    /// Wrap `inner`, caching responses in `store` for up to `ttl`.
+    pub fn with_store(inner: E, store: S, ttl: Option<Duration>) -> Self {
+        Self { inner, store, ttl }
+    }
+}
+
+impl<E: Default, S: Default> Default for CachingExecutor<E, S> {
+    fn default() -> Self {
+        Self::new(E::default())
+    }
+}
+
+/// Derives a cache key from `auth`, `path` and `params`.
+///
+/// `auth` must be folded in: the same `CachingExecutor`/`CacheStore` can end up shared across
+/// two [`Client`](super::Client)s built with different credentials (e.g. a pooled, multi-tenant
+/// server), and without this a cache hit would silently return a response that was authenticated
+/// under a different caller's credentials.
+fn cache_key(auth: &Auth, path: &str, params: &[(&'static str, Cow<'_, str>)]) -> String {
+    let auth_key = match auth {
+        Auth::ApiKey(api_key) => format!("apikey:{api_key}"),
+        Auth::BearerToken(token) => format!("bearer:{token}"),
+    };
+    let mut sorted = params.to_vec();
+    sorted.sort_by_key(|(name, _)| *name);
+    let query = sorted
+        .iter()
+        .map(|(name, value)| format!("{name}={value}"))
+        .collect::<Vec<_>>()
+        .join("&");
+    format!("{auth_key}|{path}?{query}")
+}
+
+#[async_trait]
+impl<E: Executor, S: CacheStore> Executor for CachingExecutor<E, S> {
+    async fn execute<T: DeserializeOwned + Serialize>(
+        &self,
+        base_url: &str,
+        auth: &Auth,
+        path: &str,
+        params: Vec<(&'static str, Cow<'_, str>)>,
+        cacheable: bool,
+    ) -> Result<T, Error> {
+        if !cacheable {
+            return self
+                .inner
+                .execute(base_url, auth, path, params, cacheable)
+                .await;
+        }
+
+        let key = cache_key(auth, path, &params);
+        if let Some(cached) = self.store.get(&key).await {
+            return Ok(serde_json::from_slice(&cached)?);
+        }
+
+        let value: T = self
+            .inner
+            .execute(base_url, auth, path, params, cacheable)
+            .await?;
+        let serialized = serde_json::to_vec(&value)?;
+        self.store.set(key, serialized, self.ttl).await;
+        Ok(value)
+    }
+
+    async fn download(&self, url: &str) -> Result<ByteStream, Error> {
+        self.inner.download(url).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::client::Client;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::company::alternative_names::CompanyAlternativeNames;
+    use crate::prelude::Command;
+
+    use super::CachingExecutor;
+
+    #[tokio::test]
+    async fn second_call_is_served_from_cache() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<CachingExecutor<ReqwestExecutor>>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(CachingExecutor::new(ReqwestExecutor::default()))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/company/1/alternative_names")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/company-alternative-names.json"
+            ))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cmd = CompanyAlternativeNames::new(1);
+        let first = cmd.execute(&client).await.unwrap();
+        let second = cmd.execute(&client).await.unwrap();
+
+        assert_eq!(first.id, second.id);
+        _m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn expired_entry_is_refetched() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<CachingExecutor<ReqwestExecutor>>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(CachingExecutor::with_store(
+                ReqwestExecutor::default(),
+                super::InMemoryCacheStore::new(),
+                Some(Duration::from_millis(1)),
+            ))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/company/1/alternative_names")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/company-alternative-names.json"
+            ))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let cmd = CompanyAlternativeNames::new(1);
+        cmd.execute(&client).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(5)).await;
+        cmd.execute(&client).await.unwrap();
+        _m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn max_entries_zero_stores_nothing() {
+        use super::{CacheStore, InMemoryCacheStore};
+
+        let store = InMemoryCacheStore::with_max_entries(0);
+        store.set("key".into(), b"value".to_vec(), None).await;
+
+        assert_eq!(store.get("key").await, None);
+    }
+
+    #[tokio::test]
+    async fn different_auth_is_not_served_from_other_callers_cache() {
+        let mut server = mockito::Server::new_async().await;
+        let executor = CachingExecutor::new(ReqwestExecutor::default());
+        let first_client = Client::<CachingExecutor<ReqwestExecutor>>::builder()
+            .with_api_key("first-secret".into())
+            .with_base_url(server.url())
+            .with_executor(executor.clone())
+            .build()
+            .unwrap();
+        let second_client = Client::<CachingExecutor<ReqwestExecutor>>::builder()
+            .with_api_key("second-secret".into())
+            .with_base_url(server.url())
+            .with_executor(executor)
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/company/1/alternative_names")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!(
+                "../../assets/company-alternative-names.json"
+            ))
+            .expect(2)
+            .create_async()
+            .await;
+
+        let cmd = CompanyAlternativeNames::new(1);
+        cmd.execute(&first_client).await.unwrap();
+        cmd.execute(&second_client).await.unwrap();
+        _m.assert_async().await;
+    }
+}
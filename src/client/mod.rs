@@ -0,0 +1,225 @@
+pub mod caching;
+pub mod reqwest;
+pub mod retry;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::common::configuration::{Configuration, ConfigurationDetails};
+use crate::error::Error;
+use crate::prelude::Command;
+
+/// A stream of image bytes, as returned by [`Executor::download`] and [`Client::download`].
+pub type ByteStream =
+    std::pin::Pin<Box<dyn futures_core::Stream<Item = Result<bytes::Bytes, Error>> + Send>>;
+
+const DEFAULT_BASE_URL: &str = "https://api.themoviedb.org/3";
+
+/// How a request authenticates itself against the TMDB API.
+#[derive(Clone, Debug)]
+pub enum Auth {
+    /// The v3 `api_key`, sent as a query parameter on every request.
+    ApiKey(String),
+    /// A v4 read-access token, sent as an `Authorization: Bearer` header instead of a query
+    /// parameter. Required for v4 endpoints.
+    BearerToken(String),
+}
+
+/// Performs the transport-level work of turning a [`Command`] into a response.
+///
+/// [`Client`] is generic over this trait so the same command implementations can run against a
+/// real HTTP client in production and a mock executor in tests.
+#[async_trait]
+pub trait Executor: Clone + Send + Sync + 'static {
+    /// Execute a single request and deserialize its body as `T`.
+    ///
+    /// `cacheable` mirrors [`Command::cacheable`](crate::prelude::Command::cacheable): executors
+    /// that cache responses (see [`caching::CachingExecutor`]) must skip the cache entirely for
+    /// requests where this is `false`.
+    async fn execute<T: DeserializeOwned + Serialize>(
+        &self,
+        base_url: &str,
+        auth: &Auth,
+        path: &str,
+        params: Vec<(&'static str, std::borrow::Cow<'_, str>)>,
+        cacheable: bool,
+    ) -> Result<T, Error>;
+
+    /// Download the body at `url` as a stream of chunks, without buffering it fully in memory.
+    ///
+    /// Image CDN URLs are unauthenticated, so unlike [`Executor::execute`] no `api_key` is
+    /// appended here.
+    async fn download(&self, url: &str) -> Result<ByteStream, Error>;
+}
+
+/// Entry point for all TMDB API requests.
+///
+/// `Client` is generic over an [`Executor`] so alternative transports (retrying, caching, ...)
+/// can be layered on by wrapping `E`.
+#[derive(Clone)]
+pub struct Client<E: Executor> {
+    executor: E,
+    auth: Auth,
+    base_url: String,
+    configuration: std::sync::Arc<tokio::sync::OnceCell<ConfigurationDetails>>,
+}
+
+impl<E: Executor> Client<E> {
+    /// Create a client authenticating with a v3 `api_key`, using the default executor settings.
+    pub fn new(api_key: String) -> Self
+    where
+        E: Default,
+    {
+        Self {
+            executor: E::default(),
+            auth: Auth::ApiKey(api_key),
+            base_url: DEFAULT_BASE_URL.into(),
+            configuration: Default::default(),
+        }
+    }
+
+    /// Start building a client with custom settings.
+    pub fn builder() -> ClientBuilder<E> {
+        ClientBuilder::new()
+    }
+
+    pub(crate) async fn execute<C: Command>(&self, command: &C) -> Result<C::Output, Error> {
+        self.executor
+            .execute(
+                &self.base_url,
+                &self.auth,
+                &command.path(),
+                command.params(),
+                command.cacheable(),
+            )
+            .await
+    }
+
+    /// Download the image at `url` as a stream of chunks, without buffering it fully in memory.
+    ///
+    /// `url` is expected to already be a full image URL, e.g. from
+    /// [`Image::url`](crate::common::image::Image::url).
+    pub async fn download(&self, url: &str) -> Result<ByteStream, Error> {
+        self.executor.download(url).await
+    }
+
+    /// Fetch the `/configuration` endpoint, caching the result for the lifetime of this client.
+    ///
+    /// TMDB's configuration (image base URLs and supported sizes) changes rarely, so it is
+    /// fetched at most once per client rather than once per
+    /// [`Image::url`](crate::common::image::Image::url) call.
+    pub async fn configuration(&self) -> Result<&ConfigurationDetails, Error> {
+        self.configuration
+            .get_or_try_init(|| async { Configuration.execute(self).await })
+            .await
+    }
+}
+
+/// Builder for [`Client`].
+pub struct ClientBuilder<E: Executor> {
+    executor: Option<E>,
+    auth: Option<Auth>,
+    base_url: Option<String>,
+}
+
+impl<E: Executor> ClientBuilder<E> {
+    fn new() -> Self {
+        Self {
+            executor: None,
+            auth: None,
+            base_url: None,
+        }
+    }
+
+    /// Authenticate with a v3 `api_key`, sent as a query parameter on every request.
+    pub fn with_api_key(mut self, api_key: String) -> Self {
+        self.auth = Some(Auth::ApiKey(api_key));
+        self
+    }
+
+    /// Authenticate with a v4 read-access token, sent as an `Authorization: Bearer` header.
+    pub fn with_bearer_token(mut self, token: String) -> Self {
+        self.auth = Some(Auth::BearerToken(token));
+        self
+    }
+
+    /// Override the base URL requests are sent to, e.g. to point at a mock server in tests.
+    pub fn with_base_url(mut self, base_url: String) -> Self {
+        self.base_url = Some(base_url);
+        self
+    }
+
+    /// Use a custom executor instead of the default one for `E`.
+    pub fn with_executor(mut self, executor: E) -> Self {
+        self.executor = Some(executor);
+        self
+    }
+
+    pub fn build(self) -> Result<Client<E>, Error>
+    where
+        E: Default,
+    {
+        let auth = self.auth.ok_or_else(|| {
+            Error::Builder("missing authentication, call with_api_key or with_bearer_token".into())
+        })?;
+        Ok(Client {
+            executor: self.executor.unwrap_or_default(),
+            auth,
+            base_url: self.base_url.unwrap_or_else(|| DEFAULT_BASE_URL.into()),
+            configuration: Default::default(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::Client;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::common::configuration::Configuration;
+    use crate::prelude::Command;
+
+    #[tokio::test]
+    async fn api_key_is_sent_as_query_param() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/configuration.json"))
+            .create_async()
+            .await;
+
+        Configuration.execute(&client).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn bearer_token_is_sent_as_header() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_bearer_token("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_header("authorization", "Bearer secret")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/configuration.json"))
+            .create_async()
+            .await;
+
+        Configuration.execute(&client).await.unwrap();
+    }
+}
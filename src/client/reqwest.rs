@@ -0,0 +1,110 @@
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::{Auth, ByteStream, Executor};
+use crate::error::{Error, ServerError};
+
+/// [`Executor`] backed by [`reqwest`].
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestExecutor {
+    client: reqwest::Client,
+}
+
+#[async_trait]
+impl Executor for ReqwestExecutor {
+    async fn execute<T: DeserializeOwned + Serialize>(
+        &self,
+        base_url: &str,
+        auth: &Auth,
+        path: &str,
+        mut params: Vec<(&'static str, Cow<'_, str>)>,
+        _cacheable: bool,
+    ) -> Result<T, Error> {
+        let mut request = self.client.get(format!("{}{}", base_url, path));
+        request = match auth {
+            Auth::ApiKey(api_key) => {
+                params.push(("api_key", Cow::Borrowed(api_key)));
+                request
+            }
+            Auth::BearerToken(token) => request.bearer_auth(token),
+        };
+
+        let response = request.query(&params).send().await?;
+
+        let status = response.status();
+        if status.is_success() {
+            let body = response.bytes().await?;
+            return Ok(serde_json::from_slice(&body)?);
+        }
+
+        if status.as_u16() == 429 {
+            let retry_after = response
+                .headers()
+                .get(reqwest::header::RETRY_AFTER)
+                .and_then(|value| value.to_str().ok())
+                .and_then(|value| value.parse::<u64>().ok())
+                .map(std::time::Duration::from_secs)
+                .unwrap_or(std::time::Duration::from_secs(1));
+            let body = response.bytes().await?;
+            let server_error = serde_json::from_slice(&body).ok();
+            return Err(Error::RateLimited(retry_after, server_error));
+        }
+
+        if status.is_server_error() {
+            return Err(Error::ServerUnavailable(status.as_u16()));
+        }
+
+        let body = response.bytes().await?;
+        let server_error: ServerError = serde_json::from_slice(&body)?;
+        Err(Error::Server(server_error))
+    }
+
+    async fn download(&self, url: &str) -> Result<ByteStream, Error> {
+        let response = self.client.get(url).send().await?.error_for_status()?;
+        Ok(Box::pin(futures_util::StreamExt::map(
+            response.bytes_stream(),
+            |chunk| chunk.map_err(Error::from),
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures_util::TryStreamExt;
+
+    use super::ReqwestExecutor;
+    use crate::client::Client;
+
+    #[tokio::test]
+    async fn download_streams_the_body() {
+        let mut server = mockito::Server::new_async().await;
+        let _m = server
+            .mock("GET", "/t/p/original/path.jpg")
+            .with_status(200)
+            .with_header("content-type", "image/jpeg")
+            .with_body(b"not-actually-a-jpeg".to_vec())
+            .create_async()
+            .await;
+
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let url = format!("{}/t/p/original/path.jpg", server.url());
+        let stream = client.download(&url).await.unwrap();
+        let body: Vec<u8> = stream
+            .try_fold(Vec::new(), |mut acc, chunk| async move {
+                acc.extend_from_slice(&chunk);
+                Ok(acc)
+            })
+            .await
+            .unwrap();
+
+        assert_eq!(body, b"not-actually-a-jpeg");
+    }
+}
@@ -0,0 +1,209 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use rand::Rng;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::{Auth, ByteStream, Executor};
+use crate::error::Error;
+
+const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+const BASE_BACKOFF: Duration = Duration::from_millis(500);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Wraps an [`Executor`] to transparently retry transient failures.
+///
+/// A `429` response ([`Error::RateLimited`]) is retried after sleeping for exactly the duration
+/// taken from its `Retry-After` header. A `5xx` response ([`Error::ServerUnavailable`]) is
+/// retried with an exponential backoff, doubling on each attempt up to [`MAX_BACKOFF`] and
+/// jittered to avoid a thundering herd. Every other error (invalid key, not found, ...)
+/// short-circuits immediately without consuming retry budget, since retrying it can never
+/// succeed.
+///
+/// ```rust
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::client::retry::RetryExecutor;
+///
+/// let client = Client::<RetryExecutor<ReqwestExecutor>>::builder()
+///     .with_api_key("this-is-my-secret-token".into())
+///     .with_executor(RetryExecutor::new(ReqwestExecutor::default()))
+///     .build()
+///     .unwrap();
+/// # let _ = client;
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryExecutor<E> {
+    inner: E,
+    max_attempts: u32,
+}
+
+impl<E> RetryExecutor<E> {
+    /// Wrap `inner`, retrying transient failures for at most [`DEFAULT_MAX_ATTEMPTS`] attempts in
+    /// total, the first one included.
+    pub fn new(inner: E) -> Self {
+        Self {
+            inner,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+        }
+    }
+
+    /// Wrap `inner`, retrying transient failures for at most `max_attempts` attempts in total,
+    /// the first one included.
+    pub fn with_max_attempts(inner: E, max_attempts: u32) -> Self {
+        Self {
+            inner,
+            max_attempts,
+        }
+    }
+}
+
+impl<E: Default> Default for RetryExecutor<E> {
+    fn default() -> Self {
+        Self::new(E::default())
+    }
+}
+
+#[async_trait]
+impl<E: Executor> Executor for RetryExecutor<E> {
+    async fn execute<T: DeserializeOwned + Serialize>(
+        &self,
+        base_url: &str,
+        auth: &Auth,
+        path: &str,
+        params: Vec<(&'static str, std::borrow::Cow<'_, str>)>,
+        cacheable: bool,
+    ) -> Result<T, Error> {
+        let mut attempt = 0;
+        loop {
+            attempt += 1;
+            match self
+                .inner
+                .execute(base_url, auth, path, params.clone(), cacheable)
+                .await
+            {
+                Ok(value) => return Ok(value),
+                Err(err) => match retry_delay(&err, attempt) {
+                    Some(delay) if attempt < self.max_attempts => {
+                        tokio::time::sleep(delay).await;
+                    }
+                    _ => return Err(err),
+                },
+            }
+        }
+    }
+
+    async fn download(&self, url: &str) -> Result<ByteStream, Error> {
+        self.inner.download(url).await
+    }
+}
+
+/// How long to wait before the next attempt, or `None` if `err` should not be retried at all.
+fn retry_delay(err: &Error, attempt: u32) -> Option<Duration> {
+    match err {
+        Error::RateLimited(retry_after, _) => Some(*retry_after),
+        Error::ServerUnavailable(_) => {
+            let backoff = BASE_BACKOFF
+                .saturating_mul(1 << attempt.min(6))
+                .min(MAX_BACKOFF);
+            let jitter = Duration::from_millis(rand::thread_rng().gen_range(0..250));
+            Some(backoff + jitter)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::Client;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::common::configuration::Configuration;
+    use crate::prelude::Command;
+
+    use super::RetryExecutor;
+
+    #[tokio::test]
+    async fn retries_on_429_and_honors_retry_after() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<RetryExecutor<ReqwestExecutor>>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(RetryExecutor::new(ReqwestExecutor::default()))
+            .build()
+            .unwrap();
+
+        let _rate_limited = server
+            .mock("GET", "/configuration")
+            .with_status(429)
+            .with_header("retry-after", "1")
+            .expect(1)
+            .create_async()
+            .await;
+        let _success = server
+            .mock("GET", "/configuration")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/configuration.json"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let started = tokio::time::Instant::now();
+        let result = Configuration.execute(&client).await.unwrap();
+        assert_eq!(result.images.secure_base_url, "https://image.tmdb.org/t/p/");
+        assert!(started.elapsed() >= std::time::Duration::from_secs(1));
+        _rate_limited.assert_async().await;
+        _success.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<RetryExecutor<ReqwestExecutor>>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(RetryExecutor::new(ReqwestExecutor::default()))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .expect(1)
+            .create_async()
+            .await;
+
+        let err = Configuration.execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+        _m.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<RetryExecutor<ReqwestExecutor>>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .with_executor(RetryExecutor::with_max_attempts(
+                ReqwestExecutor::default(),
+                3,
+            ))
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .with_status(503)
+            .expect(3)
+            .create_async()
+            .await;
+
+        let err = Configuration.execute(&client).await.unwrap_err();
+        assert!(matches!(err, crate::error::Error::ServerUnavailable(503)));
+        _m.assert_async().await;
+    }
+}
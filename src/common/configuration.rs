@@ -0,0 +1,128 @@
+use std::borrow::Cow;
+
+/// Get the system wide configuration, most notably the image base URLs and supported sizes.
+///
+/// This rarely changes, so [`Client::configuration`](crate::client::Client::configuration) fetches
+/// and caches it once rather than re-executing this command on every call.
+///
+/// ```rust
+/// use tmdb_api::prelude::Command;
+/// use tmdb_api::client::Client;
+/// use tmdb_api::client::reqwest::ReqwestExecutor;
+/// use tmdb_api::common::configuration::Configuration;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let client = Client::<ReqwestExecutor>::new("this-is-my-secret-token".into());
+///     let result = Configuration.execute(&client).await;
+///     match result {
+///         Ok(res) => println!("found: {:#?}", res),
+///         Err(err) => eprintln!("error: {:?}", err),
+///     };
+/// }
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Configuration;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ImagesConfiguration {
+    pub base_url: String,
+    pub secure_base_url: String,
+    pub backdrop_sizes: Vec<String>,
+    pub logo_sizes: Vec<String>,
+    pub poster_sizes: Vec<String>,
+    pub profile_sizes: Vec<String>,
+    pub still_sizes: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ConfigurationDetails {
+    pub images: ImagesConfiguration,
+    pub change_keys: Vec<String>,
+}
+
+impl crate::prelude::Command for Configuration {
+    type Output = ConfigurationDetails;
+
+    fn path(&self) -> Cow<'static, str> {
+        Cow::Borrowed("/configuration")
+    }
+
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use mockito::Matcher;
+
+    use crate::client::Client;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::prelude::Command;
+
+    use super::Configuration;
+
+    #[tokio::test]
+    async fn it_works() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/configuration.json"))
+            .create_async()
+            .await;
+
+        let result = Configuration.execute(&client).await.unwrap();
+        assert_eq!(result.images.secure_base_url, "https://image.tmdb.org/t/p/");
+    }
+
+    #[tokio::test]
+    async fn invalid_api_key() {
+        let mut server = mockito::Server::new_async().await;
+        let client = Client::<ReqwestExecutor>::builder()
+            .with_api_key("secret".into())
+            .with_base_url(server.url())
+            .build()
+            .unwrap();
+
+        let _m = server
+            .mock("GET", "/configuration")
+            .match_query(Matcher::UrlEncoded("api_key".into(), "secret".into()))
+            .with_status(401)
+            .with_header("content-type", "application/json")
+            .with_body(include_str!("../../assets/invalid-api-key.json"))
+            .create_async()
+            .await;
+
+        let err = Configuration.execute(&client).await.unwrap_err();
+        let server_err = err.as_server_error().unwrap();
+        assert_eq!(server_err.status_code, 7);
+    }
+}
+
+#[cfg(all(test, feature = "integration"))]
+mod integration_tests {
+    use crate::client::Client;
+    use crate::client::reqwest::ReqwestExecutor;
+    use crate::prelude::Command;
+
+    use super::Configuration;
+
+    #[tokio::test]
+    async fn execute() {
+        let secret = std::env::var("TMDB_TOKEN_V3").unwrap();
+        let client = Client::<ReqwestExecutor>::new(secret);
+
+        let result = Configuration.execute(&client).await.unwrap();
+        assert!(!result.images.secure_base_url.is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::common::configuration::ConfigurationDetails;
+
+/// A single image, as returned by e.g. `MovieImages` or `CompanyAlternativeNames`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Image {
+    pub aspect_ratio: f64,
+    pub file_path: String,
+    pub height: u64,
+    pub iso_639_1: Option<String>,
+    pub vote_average: f64,
+    pub vote_count: u64,
+    pub width: u64,
+}
+
+impl Image {
+    /// Build the full URL to fetch this image at the given size, e.g. `"w500"` or `"original"`.
+    ///
+    /// `size` is validated against `sizes`, the size list `config` reports for this specific
+    /// kind of image (e.g. `config.images.poster_sizes` for a poster, `backdrop_sizes` for a
+    /// backdrop); an unrecognized size falls back to `"original"`, which TMDB always supports.
+    pub fn url(&self, config: &ConfigurationDetails, sizes: &[String], size: &str) -> String {
+        let size = if sizes.iter().any(|s| s == size) {
+            size
+        } else {
+            "original"
+        };
+        format!("{}{}{}", config.images.secure_base_url, size, self.file_path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Image;
+    use crate::common::configuration::{ConfigurationDetails, ImagesConfiguration};
+
+    fn config() -> ConfigurationDetails {
+        ConfigurationDetails {
+            images: ImagesConfiguration {
+                base_url: "http://image.tmdb.org/t/p/".into(),
+                secure_base_url: "https://image.tmdb.org/t/p/".into(),
+                backdrop_sizes: vec!["w300".into(), "original".into()],
+                logo_sizes: vec!["w45".into(), "original".into()],
+                poster_sizes: vec!["w92".into(), "original".into()],
+                profile_sizes: vec!["w45".into(), "original".into()],
+                still_sizes: vec!["w92".into(), "original".into()],
+            },
+            change_keys: Vec::new(),
+        }
+    }
+
+    fn image() -> Image {
+        Image {
+            aspect_ratio: 1.5,
+            file_path: "/abc.jpg".into(),
+            height: 1080,
+            iso_639_1: None,
+            vote_average: 5.0,
+            vote_count: 10,
+            width: 1920,
+        }
+    }
+
+    #[test]
+    fn builds_url_for_known_size() {
+        let config = config();
+        assert_eq!(
+            image().url(&config, &config.images.backdrop_sizes, "w300"),
+            "https://image.tmdb.org/t/p/w300/abc.jpg"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_original_for_unknown_size() {
+        let config = config();
+        assert_eq!(
+            image().url(&config, &config.images.backdrop_sizes, "w9999"),
+            "https://image.tmdb.org/t/p/original/abc.jpg"
+        );
+    }
+
+    #[test]
+    fn falls_back_to_original_for_size_from_a_different_image_kind() {
+        let config = config();
+        assert_eq!(
+            image().url(&config, &config.images.backdrop_sizes, "w92"),
+            "https://image.tmdb.org/t/p/original/abc.jpg"
+        );
+    }
+}
@@ -0,0 +1 @@
+pub mod alternative_names;
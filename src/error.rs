@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use thiserror::Error;
+
+/// Error returned by the TMDB API itself, as opposed to a transport-level failure.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ServerError {
+    /// TMDB-specific status code, e.g. `7` for an invalid API key or `34` for not found.
+    pub status_code: u32,
+    /// Human readable message describing the error.
+    pub status_message: String,
+}
+
+/// Errors that can occur while building a [`Client`](crate::client::Client) or executing a
+/// [`Command`](crate::prelude::Command).
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("server returned an error: {0:?}")]
+    Server(ServerError),
+    /// TMDB is rate limiting this API key. The `Duration` is taken from the `Retry-After`
+    /// header, or is one second when that header is missing or not a whole number of seconds;
+    /// the `ServerError` is the JSON body TMDB sends alongside the `429`, if any.
+    #[error("rate limited, retry after {0:?}: {1:?}")]
+    RateLimited(Duration, Option<ServerError>),
+    /// TMDB returned a `5xx` status, e.g. during an outage. The response body is not read.
+    #[error("server unavailable, status {0}")]
+    ServerUnavailable(u16),
+    #[error("failed to build request: {0}")]
+    Reqwest(#[from] reqwest::Error),
+    #[error("failed to decode response: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("invalid client configuration: {0}")]
+    Builder(String),
+}
+
+impl Error {
+    /// Returns the [`ServerError`] if this error originated from the TMDB API.
+    pub fn as_server_error(&self) -> Option<&ServerError> {
+        match self {
+            Error::Server(err) => Some(err),
+            Error::RateLimited(_, err) => err.as_ref(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! An async client for [The Movie Database](https://www.themoviedb.org/) (TMDB) API.
+
+#[macro_use]
+extern crate serde_derive;
+
+pub mod client;
+pub mod common;
+pub mod company;
+pub mod error;
+pub mod movie;
+pub mod prelude;
+pub mod util;
@@ -0,0 +1,36 @@
+use std::borrow::Cow;
+
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use crate::client::{Client, Executor};
+use crate::error::Error;
+
+/// A single TMDB API request.
+///
+/// Implementors describe the path and query parameters of a request; [`Command::execute`]
+/// drives an [`Executor`] to perform it and deserialize the response as `Output`.
+#[async_trait]
+pub trait Command {
+    /// The type the response body is deserialized into.
+    type Output: DeserializeOwned + Serialize;
+
+    /// The path of the request, relative to the API base URL, e.g. `/movie/550`.
+    fn path(&self) -> Cow<'static, str>;
+
+    /// The query parameters of the request, not including authentication.
+    fn params(&self) -> Vec<(&'static str, Cow<'_, str>)>;
+
+    /// Whether the response to this command may be cached by a caching executor.
+    ///
+    /// Defaults to `true`; commands whose results must always be fresh can override this.
+    fn cacheable(&self) -> bool {
+        true
+    }
+
+    /// Execute this command against the given client.
+    async fn execute<E: Executor>(&self, client: &Client<E>) -> Result<Self::Output, Error> {
+        client.execute(self).await
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Deserializer};
+
+/// Deserialize a string, treating an empty string as `None`.
+///
+/// TMDB represents an absent value as `""` rather than omitting the field or using `null`, so
+/// this is used wherever a field is documented as "optional" but always present in the payload.
+pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    if value.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(value))
+    }
+}
@@ -0,0 +1 @@
+pub mod empty_string;